@@ -9,6 +9,7 @@ use bevy::{
 };
 use itertools::Itertools;
 use std::ops::{Deref, DerefMut};
+use std::time::Duration;
 
 use crate::{ElementsBuilder, PointerInput, WithElements};
 
@@ -87,17 +88,22 @@ impl<'a, 'w, 's, 'c, S: Signal> DerefMut for ConnectionEntityContext<'a, 'w, 's,
     }
 }
 
+pub type GeneralHandler<S> = Box<dyn FnMut(&mut ConnectionGeneralContext<S>) + Send + Sync>;
+pub type EntityHandler<S> = Box<dyn FnMut(&mut ConnectionEntityContext<S>) + Send + Sync>;
+pub type ComponentHandler<C, S> =
+    Box<dyn FnMut(&mut ConnectionEntityContext<S>, &mut Mut<C>) + Send + Sync>;
+
 pub enum ConnectionTo<C: Component, S: Signal> {
     General {
-        handler: fn(&mut ConnectionGeneralContext<S>),
+        handler: GeneralHandler<S>,
     },
     Entity {
         target: Entity,
-        handler: fn(&mut ConnectionEntityContext<S>),
+        handler: EntityHandler<S>,
     },
     Component {
         target: Entity,
-        handler: fn(&mut ConnectionEntityContext<S>, &mut Mut<C>),
+        handler: ComponentHandler<C, S>,
     },
 }
 
@@ -107,15 +113,20 @@ pub struct WithoutComponent;
 impl<C: Component, S: Signal> ConnectionTo<C, S> {
     pub fn component(
         target: Entity,
-        handler: fn(&mut ConnectionEntityContext<S>, &mut Mut<C>),
+        handler: impl FnMut(&mut ConnectionEntityContext<S>, &mut Mut<C>) + Send + Sync + 'static,
     ) -> ConnectionTo<C, S> {
-        ConnectionTo::Component { target, handler }
+        ConnectionTo::Component {
+            target,
+            handler: Box::new(handler),
+        }
     }
 
     pub fn filter(self, filter: fn(&S) -> bool) -> Connection<C, S> {
         Connection {
             target: self,
-            filter,
+            filters: vec![filter],
+            min_interval: None,
+            last_fired: None,
         }
     }
 
@@ -131,26 +142,64 @@ impl<C: Component, S: Signal> ConnectionTo<C, S> {
 impl<S: Signal> ConnectionTo<WithoutComponent, S> {
     pub fn entity(
         target: Entity,
-        handler: fn(&mut ConnectionEntityContext<S>),
+        handler: impl FnMut(&mut ConnectionEntityContext<S>) + Send + Sync + 'static,
     ) -> ConnectionTo<WithoutComponent, S> {
-        ConnectionTo::Entity { target, handler }
+        ConnectionTo::Entity {
+            target,
+            handler: Box::new(handler),
+        }
     }
 
     pub fn general(
-        handler: fn(&mut ConnectionGeneralContext<S>),
+        handler: impl FnMut(&mut ConnectionGeneralContext<S>) + Send + Sync + 'static,
     ) -> ConnectionTo<WithoutComponent, S> {
-        ConnectionTo::General { handler }
+        ConnectionTo::General {
+            handler: Box::new(handler),
+        }
     }
 }
 
 pub struct Connection<C: Component, S: Signal> {
     pub target: ConnectionTo<C, S>,
-    filter: fn(&S) -> bool,
+    filters: Vec<fn(&S) -> bool>,
+    min_interval: Option<Duration>,
+    // A `Connection` only ever lives under the single `source` key it was
+    // inserted at in `Connections::map`, so `handles()` is always evaluated
+    // against that same source; no need to key this by `Entity`.
+    last_fired: Option<f64>,
 }
 
 impl<C: Component, S: Signal> Connection<C, S> {
-    pub fn handles(&self, signal: &S) -> bool {
-        (self.filter)(signal)
+    pub fn filter(mut self, filter: fn(&S) -> bool) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    pub fn debounce(mut self, min_interval: Duration) -> Self {
+        self.min_interval = Some(min_interval);
+        self
+    }
+
+    /// Returns whether the signal should be dispatched to this connection's
+    /// handler, evaluating the filter chain and then the debounce window.
+    /// Takes `&mut self` because a pass can record a new `last_fired`
+    /// timestamp; the signals processor must hold `Connections` mutably
+    /// (via `DerefMut`) to call this and to invoke the boxed `FnMut` handler.
+    pub fn handles(&mut self, ctx: &ConnectionGeneralContext<S>) -> bool {
+        if !self.filters.iter().all(|filter| filter(ctx.event())) {
+            return false;
+        }
+        let Some(min_interval) = self.min_interval else {
+            return true;
+        };
+        let now = ctx.time().elapsed_seconds_f64();
+        if let Some(last_fired) = self.last_fired {
+            if now < last_fired + min_interval.as_secs_f64() {
+                return false;
+            }
+        }
+        self.last_fired = Some(now);
+        true
     }
 
     pub fn from(self, source: Entity) -> Connect<C, S> {
@@ -204,6 +253,15 @@ impl<C: Component, S: Signal> Deref for Connections<C, S> {
     }
 }
 
+// `Connection::handles` now takes `&mut self` (it records debounce timestamps)
+// and its handlers are boxed `FnMut`, so the signals processor needs mutable
+// access to run them; `Deref` alone no longer suffices.
+impl<C: Component, S: Signal> DerefMut for Connections<C, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.map
+    }
+}
+
 impl<C: Component, S: Signal> Default for Connections<C, S> {
     fn default() -> Self {
         Connections {
@@ -243,28 +301,28 @@ macro_rules! connect {
     ($entity:expr, |$ctx:ident, $arg:ident: $typ:ty| $cb:expr) => {
         $crate::relations::ConnectionTo::component(
             $entity,
-            |$ctx, $arg: &mut ::bevy::prelude::Mut<$typ>| $cb,
+            move |$ctx, $arg: &mut ::bevy::prelude::Mut<$typ>| $cb,
         )
     };
     ($entity:expr, |$ctx:ident, $arg:ident: $typ:ty| $cb:block) => {
         $crate::relations::ConnectionTo::component(
             $entity,
-            |$ctx, $arg: &mut ::bevy::prelude::Mut<$typ>| $cb,
+            move |$ctx, $arg: &mut ::bevy::prelude::Mut<$typ>| $cb,
         )
     };
     ($entity:expr, |$arg:ident: $typ:ty| $cb:expr) => {
         $crate::relations::ConnectionTo::component(
             $entity,
-            |_, $arg: &mut ::bevy::prelude::Mut<$typ>| $cb,
+            move |_, $arg: &mut ::bevy::prelude::Mut<$typ>| $cb,
         )
     };
     ($entity:expr, |$arg:ident: $typ:ty| $cb:block) => {
-        $crate::relations::ConnectionTo::component($entity, |_, $arg| $cb)
+        $crate::relations::ConnectionTo::component($entity, move |_, $arg| $cb)
     };
     ($entity:expr, |$ctx:ident| $cb:expr) => {
-        $crate::relations::ConnectionTo::entity($entity, |$ctx| $cb)
+        $crate::relations::ConnectionTo::entity($entity, move |$ctx| $cb)
     };
     (|$ctx:ident| $cb:expr) => {
-        $crate::relations::ConnectionTo::general(|$ctx| $cb)
+        $crate::relations::ConnectionTo::general(move |$ctx| $cb)
     };
 }
\ No newline at end of file
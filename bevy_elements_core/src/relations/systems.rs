@@ -0,0 +1,111 @@
+use std::{
+    any::TypeId,
+    ops::DerefMut,
+    sync::{Arc, RwLock},
+};
+
+use bevy::{app::CoreSchedule, ecs::schedule::Schedules, prelude::*, utils::HashSet};
+
+use super::connect::{
+    ConnectionEntityContext, ConnectionGeneralContext, ConnectionTo, Connections, Signal,
+};
+
+/// Runs the connections registered for one `(Component, Signal)` pair: reads
+/// the signal's sources, evaluates each matching `Connection`'s filter/debounce
+/// chain via `Connection::handles`, and invokes the boxed handler that survives.
+fn process_signals<C: Component, S: Signal>(
+    mut events: EventReader<S>,
+    mut connections: ResMut<Connections<C, S>>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    mut components: Query<&mut C>,
+) {
+    for signal in events.iter() {
+        for &source in signal.sources() {
+            let Some(len) = connections.get(&source).map(Vec::len) else {
+                continue;
+            };
+            let mut ctx = ConnectionGeneralContext {
+                source_event: signal,
+                source,
+                time_resource: &time,
+                asset_server: asset_server.clone(),
+                commands: &mut commands,
+            };
+            for idx in 0..len {
+                let Some(connection) = connections
+                    .deref_mut()
+                    .get_mut(&source)
+                    .and_then(|targets| targets.get_mut(idx))
+                else {
+                    continue;
+                };
+                if !connection.handles(&ctx) {
+                    continue;
+                }
+                match &mut connection.target {
+                    ConnectionTo::General { handler } => handler(&mut ctx),
+                    ConnectionTo::Entity { target, handler } => {
+                        let mut entity_ctx = ConnectionEntityContext {
+                            target: *target,
+                            ctx: &mut ctx,
+                        };
+                        handler(&mut entity_ctx);
+                    }
+                    ConnectionTo::Component { target, handler } => {
+                        if let Ok(mut component) = components.get_mut(*target) {
+                            let mut entity_ctx = ConnectionEntityContext {
+                                target: *target,
+                                ctx: &mut ctx,
+                            };
+                            handler(&mut entity_ctx, &mut component);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct RelationsSystemsInner {
+    registered: HashSet<TypeId>,
+    pending: Vec<Box<dyn FnOnce(&mut World) + Send + Sync>>,
+}
+
+impl RelationsSystemsInner {
+    /// Queues `process_signals::<C, S>` for insertion into the app's
+    /// schedule the next time `RelationsSystems::flush` runs, skipping the
+    /// type pair if it was already queued.
+    pub fn add_signals_processor<C: Component, S: Signal>(&mut self) {
+        if !self.registered.insert(TypeId::of::<(C, S)>()) {
+            return;
+        }
+        self.pending.push(Box::new(|world: &mut World| {
+            world.resource_scope(|_world, mut schedules: Mut<Schedules>| {
+                schedules.add_systems_to_schedule(
+                    CoreSchedule::Main,
+                    (process_signals::<C, S>,),
+                );
+            });
+        }));
+    }
+}
+
+/// Lazily registers the signals-processing system for every `(Component,
+/// Signal)` pair that has been connected via `Connect::write`.
+#[derive(Resource, Clone, Default)]
+pub struct RelationsSystems(pub(crate) Arc<RwLock<RelationsSystemsInner>>);
+
+impl RelationsSystems {
+    /// Inserts any processors queued since the last flush into the app's
+    /// schedule. Called once per frame by the plugin that owns this
+    /// resource's startup wiring.
+    pub fn flush(&self, world: &mut World) {
+        let pending = std::mem::take(&mut self.0.write().unwrap().pending);
+        for register in pending {
+            register(world);
+        }
+    }
+}
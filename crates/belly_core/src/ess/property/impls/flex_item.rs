@@ -0,0 +1,113 @@
+use super::parse;
+use crate::style_property;
+use bevy::prelude::*;
+
+style_property! {
+    #[doc = " Specify how much a flex item grows relative to its siblings by providing"]
+    #[doc = " a value to `Style.flex_grow`:"]
+    #[doc = " ```css"]
+    #[doc = " flex-grow: 1;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " The `flex-grow` property sets the flex grow factor, which specifies how"]
+    #[doc = " much of the flex container's remaining space should be assigned to the"]
+    #[doc = " item relative to the other flex items. Negative values are invalid."]
+    #[doc = " <!-- @property-category=Flex Item -->"]
+    FlexGrowProperty("flex-grow") {
+        Default = "0";
+        Item = f32;
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::FloatParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            if style.flex_grow != *value {
+                style.flex_grow = *value;
+            }
+        };
+    }
+}
+
+style_property! {
+    #[doc = " Specify how much a flex item shrinks relative to its siblings by providing"]
+    #[doc = " a value to `Style.flex_shrink`:"]
+    #[doc = " ```css"]
+    #[doc = " flex-shrink: 0;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " The `flex-shrink` property sets the flex shrink factor, which specifies"]
+    #[doc = " how much the item should shrink relative to the other flex items when"]
+    #[doc = " there isn't enough space in the container. Negative values are invalid."]
+    #[doc = " <!-- @property-category=Flex Item -->"]
+    FlexShrinkProperty("flex-shrink") {
+        Default = "1";
+        Item = f32;
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::FloatParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            if style.flex_shrink != *value {
+                style.flex_shrink = *value;
+            }
+        };
+    }
+}
+
+style_property! {
+    #[doc = " Specify the initial main-size of a flex item by providing a value to"]
+    #[doc = " `Style.flex_basis`:"]
+    #[doc = " ```css"]
+    #[doc = " flex-basis: 30px;"]
+    #[doc = " flex-basis: auto;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " The `flex-basis` property sets the initial main-size of a flex item,"]
+    #[doc = " before the remaining space is distributed according to `flex-grow` and"]
+    #[doc = " `flex-shrink`."]
+    #[doc = " <!-- @property-category=Flex Item -->"]
+    FlexBasisProperty("flex-basis") {
+        Default = "auto";
+        Item = Val;
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::ValParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            if style.flex_basis != *value {
+                style.flex_basis = *value;
+            }
+        };
+    }
+}
+
+style_property! {
+    #[doc = " Override the `align-items` value of the parent container for this"]
+    #[doc = " particular item by providing a value to `Style.align_self`:"]
+    #[doc = " ```css"]
+    #[doc = " align-self: center;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " Supported values:"]
+    #[doc = " - `auto`: The item follows its parent container's `align-items` value."]
+    #[doc = " - `flex-start`: The cross-start margin edge of the item is flushed with"]
+    #[doc = "   the cross-start edge of the line."]
+    #[doc = " - `flex-end`: The cross-end margin edge of the item is flushed with the"]
+    #[doc = "   cross-end edge of the line."]
+    #[doc = " - `center`: The item's margin box is centered within the line on the"]
+    #[doc = "   cross-axis."]
+    #[doc = " - `baseline`: The item is aligned such that its baseline aligns with the"]
+    #[doc = "   other items sharing the line's baseline."]
+    #[doc = " - `stretch`: The item is stretched to fill the line while respecting"]
+    #[doc = "   width and height constraints."]
+    #[doc = " <!-- @property-category=Flex Item -->"]
+    AlignSelfProperty("align-self") {
+        Default = "auto";
+        Item = AlignSelf;
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::IdentifierParser<AlignSelf>;
+        Apply = |value, style, _assets, _commands, _entity| {
+            if &style.align_self != value {
+                style.align_self = *value;
+            }
+        };
+    }
+}
@@ -0,0 +1,76 @@
+use super::parse;
+use crate::style_property;
+use bevy::prelude::*;
+
+style_property! {
+    #[doc = " Specify the spacing between flex items by providing one or two `Val`"]
+    #[doc = " lengths to `Style.gap`:"]
+    #[doc = " ```css"]
+    #[doc = " gap: 8px;"]
+    #[doc = " gap: 8px 16px;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " When a single value is given it is applied to both the row and the"]
+    #[doc = " column gap. When two values are given the first sets the row gap"]
+    #[doc = " (`Style.gap.height`) and the second sets the column gap"]
+    #[doc = " (`Style.gap.width`), matching the CSS flexbox `gap` shorthand."]
+    #[doc = " <!-- @property-category=Flex Container -->"]
+    GapProperty("gap") {
+        Default = "0px";
+        Item = (Val, Val);
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::GapParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            let (row, column) = *value;
+            if style.gap.height != row {
+                style.gap.height = row;
+            }
+            if style.gap.width != column {
+                style.gap.width = column;
+            }
+        };
+    }
+}
+
+style_property! {
+    #[doc = " Specify the spacing between flex item rows by providing a `Val` length"]
+    #[doc = " to `Style.gap.height`:"]
+    #[doc = " ```css"]
+    #[doc = " row-gap: 8px;"]
+    #[doc = " ```"]
+    #[doc = " <!-- @property-category=Flex Container -->"]
+    RowGapProperty("row-gap") {
+        Default = "0px";
+        Item = Val;
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::ValParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            if style.gap.height != *value {
+                style.gap.height = *value;
+            }
+        };
+    }
+}
+
+style_property! {
+    #[doc = " Specify the spacing between flex item columns by providing a `Val`"]
+    #[doc = " length to `Style.gap.width`:"]
+    #[doc = " ```css"]
+    #[doc = " column-gap: 16px;"]
+    #[doc = " ```"]
+    #[doc = " <!-- @property-category=Flex Container -->"]
+    ColumnGapProperty("column-gap") {
+        Default = "0px";
+        Item = Val;
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::ValParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            if style.gap.width != *value {
+                style.gap.width = *value;
+            }
+        };
+    }
+}
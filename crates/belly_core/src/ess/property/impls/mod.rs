@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+use super::PropertyPlugin;
+
+mod flex_container;
+mod flex_item;
+mod gap;
+mod overflow;
+mod size_constraints;
+
+pub use flex_container::*;
+pub use flex_item::*;
+pub use gap::*;
+pub use overflow::*;
+pub use size_constraints::*;
+
+pub(super) fn add_properties(app: &mut App) {
+    app.add_plugin(PropertyPlugin::<FlexDirectionProperty>::default());
+    app.add_plugin(PropertyPlugin::<FlexWrapProperty>::default());
+    app.add_plugin(PropertyPlugin::<AlignItemsProperty>::default());
+    app.add_plugin(PropertyPlugin::<AlignContentProperty>::default());
+    app.add_plugin(PropertyPlugin::<JustifyContentProperty>::default());
+
+    app.add_plugin(PropertyPlugin::<FlexGrowProperty>::default());
+    app.add_plugin(PropertyPlugin::<FlexShrinkProperty>::default());
+    app.add_plugin(PropertyPlugin::<FlexBasisProperty>::default());
+    app.add_plugin(PropertyPlugin::<AlignSelfProperty>::default());
+
+    app.add_plugin(PropertyPlugin::<OverflowProperty>::default());
+
+    app.add_plugin(PropertyPlugin::<GapProperty>::default());
+    app.add_plugin(PropertyPlugin::<RowGapProperty>::default());
+    app.add_plugin(PropertyPlugin::<ColumnGapProperty>::default());
+
+    app.add_plugin(PropertyPlugin::<MinWidthProperty>::default());
+    app.add_plugin(PropertyPlugin::<MaxWidthProperty>::default());
+    app.add_plugin(PropertyPlugin::<MinHeightProperty>::default());
+    app.add_plugin(PropertyPlugin::<MaxHeightProperty>::default());
+    app.add_plugin(PropertyPlugin::<MinSizeProperty>::default());
+    app.add_plugin(PropertyPlugin::<MaxSizeProperty>::default());
+}
@@ -0,0 +1,34 @@
+use super::parse;
+use crate::style_property;
+use bevy::prelude::*;
+
+style_property! {
+    #[doc = " Specify whether content that overflows an element's box is clipped by"]
+    #[doc = " providing a value to `Style.overflow`:"]
+    #[doc = " ```css"]
+    #[doc = " overflow: hidden;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " Supported values:"]
+    #[doc = " - `visible`: Content is not clipped and may render outside the element's box."]
+    #[doc = " - `hidden`: Content is clipped to the element's box, no scrollbars are shown."]
+    #[doc = " "]
+    #[doc = " The pinned Bevy's `Style.overflow` is a single value shared by both"]
+    #[doc = " axes (no `x`/`y` split and no `clip` variant), so the independent"]
+    #[doc = " `overflow-x`/`overflow-y` longhands and the two-value shorthand asked"]
+    #[doc = " for by the original request are deferred until belly depends on a"]
+    #[doc = " Bevy version that exposes per-axis overflow."]
+    #[doc = " <!-- @property-category=Flex Container -->"]
+    OverflowProperty("overflow") {
+        Default = "visible";
+        Item = Overflow;
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::IdentifierParser<Overflow>;
+        Apply = |value, style, _assets, _commands, _entity| {
+            if &style.overflow != value {
+                style.overflow = *value;
+            }
+        };
+    }
+}
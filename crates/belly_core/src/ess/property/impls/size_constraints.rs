@@ -0,0 +1,174 @@
+use super::parse;
+use crate::style_property;
+use bevy::prelude::*;
+
+/// Rounds a resolved pixel length away from zero so constrained elements
+/// land on whole pixels instead of blurring at the sub-pixel boundary.
+fn round_away_from_zero(value: Val) -> Val {
+    match value {
+        Val::Px(px) => Val::Px(px.abs().ceil().copysign(px)),
+        other => other,
+    }
+}
+
+style_property! {
+    #[doc = " Specify the minimum width an element is allowed to shrink to by"]
+    #[doc = " providing a value to `Style.min_size.width`:"]
+    #[doc = " ```css"]
+    #[doc = " min-width: 120px;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " Pixel values are rounded away from zero so the constrained width snaps"]
+    #[doc = " to a whole pixel."]
+    #[doc = " <!-- @property-category=Size -->"]
+    MinWidthProperty("min-width") {
+        Default = "auto";
+        Item = Val;
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::ValParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            let value = round_away_from_zero(*value);
+            if style.min_size.width != value {
+                style.min_size.width = value;
+            }
+        };
+    }
+}
+
+style_property! {
+    #[doc = " Specify the maximum width an element is allowed to grow to by"]
+    #[doc = " providing a value to `Style.max_size.width`:"]
+    #[doc = " ```css"]
+    #[doc = " max-width: 480px;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " Pixel values are rounded away from zero so the constrained width snaps"]
+    #[doc = " to a whole pixel."]
+    #[doc = " <!-- @property-category=Size -->"]
+    MaxWidthProperty("max-width") {
+        Default = "auto";
+        Item = Val;
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::ValParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            let value = round_away_from_zero(*value);
+            if style.max_size.width != value {
+                style.max_size.width = value;
+            }
+        };
+    }
+}
+
+style_property! {
+    #[doc = " Specify the minimum height an element is allowed to shrink to by"]
+    #[doc = " providing a value to `Style.min_size.height`:"]
+    #[doc = " ```css"]
+    #[doc = " min-height: 32px;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " Pixel values are rounded away from zero so the constrained height snaps"]
+    #[doc = " to a whole pixel."]
+    #[doc = " <!-- @property-category=Size -->"]
+    MinHeightProperty("min-height") {
+        Default = "auto";
+        Item = Val;
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::ValParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            let value = round_away_from_zero(*value);
+            if style.min_size.height != value {
+                style.min_size.height = value;
+            }
+        };
+    }
+}
+
+style_property! {
+    #[doc = " Specify the maximum height an element is allowed to grow to by"]
+    #[doc = " providing a value to `Style.max_size.height`:"]
+    #[doc = " ```css"]
+    #[doc = " max-height: 240px;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " Pixel values are rounded away from zero so the constrained height snaps"]
+    #[doc = " to a whole pixel."]
+    #[doc = " <!-- @property-category=Size -->"]
+    MaxHeightProperty("max-height") {
+        Default = "auto";
+        Item = Val;
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::ValParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            let value = round_away_from_zero(*value);
+            if style.max_size.height != value {
+                style.max_size.height = value;
+            }
+        };
+    }
+}
+
+style_property! {
+    #[doc = " Shorthand for setting both the minimum width and minimum height by"]
+    #[doc = " providing one or two `Val` lengths to `Style.min_size`:"]
+    #[doc = " ```css"]
+    #[doc = " min-size: 120px;"]
+    #[doc = " min-size: 120px 32px;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " When a single value is given it is applied to both width and height."]
+    #[doc = " When two values are given the first sets the width and the second sets"]
+    #[doc = " the height. This is the same one-or-two-value form as `gap`, but `gap`"]
+    #[doc = " takes its vertical (row) value first."]
+    #[doc = " <!-- @property-category=Size -->"]
+    MinSizeProperty("min-size") {
+        Default = "auto";
+        Item = (Val, Val);
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::SizeParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            let (width, height) = (round_away_from_zero(value.0), round_away_from_zero(value.1));
+            if style.min_size.width != width {
+                style.min_size.width = width;
+            }
+            if style.min_size.height != height {
+                style.min_size.height = height;
+            }
+        };
+    }
+}
+
+style_property! {
+    #[doc = " Shorthand for setting both the maximum width and maximum height by"]
+    #[doc = " providing one or two `Val` lengths to `Style.max_size`:"]
+    #[doc = " ```css"]
+    #[doc = " max-size: 480px;"]
+    #[doc = " max-size: 480px 240px;"]
+    #[doc = " ```"]
+    #[doc = " "]
+    #[doc = " When a single value is given it is applied to both width and height."]
+    #[doc = " When two values are given the first sets the width and the second sets"]
+    #[doc = " the height. This is the same one-or-two-value form as `gap`, but `gap`"]
+    #[doc = " takes its vertical (row) value first."]
+    #[doc = " <!-- @property-category=Size -->"]
+    MaxSizeProperty("max-size") {
+        Default = "auto";
+        Item = (Val, Val);
+        Components = &'static mut Style;
+        Filters = With<Node>;
+        Parser = parse::SizeParser;
+        Apply = |value, style, _assets, _commands, _entity| {
+            let (width, height) = (round_away_from_zero(value.0), round_away_from_zero(value.1));
+            if style.max_size.width != width {
+                style.max_size.width = width;
+            }
+            if style.max_size.height != height {
+                style.max_size.height = height;
+            }
+        };
+    }
+}
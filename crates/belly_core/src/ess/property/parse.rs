@@ -0,0 +1,50 @@
+use bevy::prelude::Val;
+
+/// Parses a non-negative float, used by `flex-grow`/`flex-shrink`. Negative
+/// input is rejected outright (the property falls back to its default)
+/// rather than silently clamped or flipped to a positive sign.
+///
+/// Mirrors the `parse(input: &str) -> Option<Output>` convention of the
+/// pre-existing `IdentifierParser`/`ValParser` rather than introducing a new
+/// parsing trait, so `style_property!` dispatches to it the same way.
+pub struct FloatParser;
+impl FloatParser {
+    pub fn parse(input: &str) -> Option<f32> {
+        let value: f32 = input.trim().parse().ok()?;
+        (value >= 0.0).then_some(value)
+    }
+}
+
+/// Parses a CSS-style one-or-two-`Val` shorthand: a single value applies to
+/// both slots, two values fill them in order, and anything past the second
+/// is rejected.
+fn parse_one_or_two_vals(input: &str) -> Option<(Val, Val)> {
+    let mut values = input.split_whitespace().map(ValParser::parse);
+    let first = values.next()??;
+    let second = match values.next() {
+        Some(second) => second?,
+        None => first,
+    };
+    if values.next().is_some() {
+        return None;
+    }
+    Some((first, second))
+}
+
+/// Parses the `gap` shorthand: one `Val` applies to both row and column, two
+/// apply to row then column, matching the CSS flexbox `gap` shorthand.
+pub struct GapParser;
+impl GapParser {
+    pub fn parse(input: &str) -> Option<(Val, Val)> {
+        parse_one_or_two_vals(input)
+    }
+}
+
+/// Parses the `min-size`/`max-size` shorthands: one `Val` applies to both
+/// width and height, two apply to width then height.
+pub struct SizeParser;
+impl SizeParser {
+    pub fn parse(input: &str) -> Option<(Val, Val)> {
+        parse_one_or_two_vals(input)
+    }
+}